@@ -1,9 +1,135 @@
 use std::f64::consts::PI;
 
+/// Radius of the WGS84 sphere, in meters.
+const EARTH_RADIUS: f64 = 6378137.0;
+
+/// Half the equatorial circumference of the earth, in meters (`PI * EARTH_RADIUS`). This is the
+/// distance from the center of the EPSG:3857 projection to either edge along each axis.
+const ORIGIN_SHIFT: f64 = PI * EARTH_RADIUS;
+
 pub struct Mercator {
     tile_size: f64,
 }
 
+/// The row ordering convention a tile's `y` index is expressed in.
+///
+/// The two conventions agree on the column but number rows from opposite edges of the map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TileOrigin {
+    /// TMS ordering, with row `0` at the bottom-left of the map.
+    Tms,
+    /// Google/OSM "slippy" XYZ ordering, with row `0` at the top-left of the map.
+    Google,
+}
+
+/// A single map tile in a tile pyramid, addressed by its column (`x`), row (`y`) and zoom level
+/// (`z`).
+///
+/// The `origin` records which row ordering the `y` index follows; use [`Tile::tms_to_google`] and
+/// [`Tile::google_to_tms`] to move between the two conventions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tile {
+    /// Tile column.
+    pub x: i64,
+    /// Tile row.
+    pub y: i64,
+    /// Zoom level the tile belongs to.
+    pub z: usize,
+    /// The row ordering convention `y` is expressed in.
+    pub origin: TileOrigin,
+}
+
+impl Tile {
+    /// Reinterprets a TMS tile as a Google/XYZ tile by flipping its row as
+    /// `y' = (2 ^ z - 1) - y`.
+    ///
+    /// The flip uses the tile's own zoom level `z`. A tile that is already in Google ordering is
+    /// returned unchanged.
+    pub fn tms_to_google(&self) -> Tile {
+        match self.origin {
+            TileOrigin::Google => *self,
+            TileOrigin::Tms => Tile {
+                x: self.x,
+                y: (1_i64 << self.z) - 1 - self.y,
+                z: self.z,
+                origin: TileOrigin::Google,
+            },
+        }
+    }
+
+    /// Reinterprets a Google/XYZ tile as a TMS tile by flipping its row as
+    /// `y' = (2 ^ z - 1) - y`.
+    ///
+    /// The flip uses the tile's own zoom level `z`. A tile that is already in TMS ordering is
+    /// returned unchanged.
+    pub fn google_to_tms(&self) -> Tile {
+        match self.origin {
+            TileOrigin::Tms => *self,
+            TileOrigin::Google => Tile {
+                x: self.x,
+                y: (1_i64 << self.z) - 1 - self.y,
+                z: self.z,
+                origin: TileOrigin::Tms,
+            },
+        }
+    }
+
+    /// Encodes the tile as a Microsoft/Bing quadkey.
+    ///
+    /// The quadkey interleaves the column and row bits from the most significant bit down, giving
+    /// a string of length `z` over the alphabet `0-3`.
+    pub fn to_quadkey(&self) -> String {
+        // Quadkeys number rows from the top, so TMS rows are flipped first.
+        let tile = self.tms_to_google();
+        let mut quadkey = String::with_capacity(tile.z);
+        let mut i = tile.z;
+
+        while i >= 1 {
+            let mut digit = 0u8;
+            let mask = 1_i64 << (i - 1);
+
+            if tile.x & mask != 0 {
+                digit += 1;
+            }
+            if tile.y & mask != 0 {
+                digit += 2;
+            }
+
+            quadkey.push((b'0' + digit) as char);
+            i -= 1;
+        }
+
+        quadkey
+    }
+
+    /// Decodes a Microsoft/Bing quadkey back into a tile, using the string length as the zoom level.
+    ///
+    /// Returns `None` if the quadkey contains any character outside `0-3`. The resulting tile uses
+    /// Google/XYZ row ordering, matching Bing's top-left origin.
+    pub fn from_quadkey(quadkey: &str) -> Option<Tile> {
+        let z = quadkey.len();
+        let mut x = 0_i64;
+        let mut y = 0_i64;
+
+        for (i, c) in quadkey.chars().enumerate() {
+            let mask = 1_i64 << (z - 1 - i);
+
+            match c {
+                '0' => {}
+                '1' => x |= mask,
+                '2' => y |= mask,
+                '3' => {
+                    x |= mask;
+                    y |= mask;
+                }
+                _ => return None,
+            }
+        }
+
+        Some(Tile { x, y, z, origin: TileOrigin::Google })
+    }
+}
+
 impl Mercator {
     /// Create a new Mercator with custom tile size. Tile sizes must be a power of two (256, 512,
     /// and so on).
@@ -11,26 +137,51 @@ impl Mercator {
         Mercator { tile_size: tile_size as f64 }
     }
 
+    /// Computes the per-zoom projection constants `(bc, cc, d)` shared by every point at a given
+    /// zoom level. Pulling this out of the per-point math lets the batch methods compute it once.
+    fn zoom_constants(&self, zoom: usize) -> (f64, f64, f64) {
+        let c = self.tile_size * 2.0_f64.powi(zoom as i32);
+
+        (c / 360.0, c / (2.0 * PI), c / 2.0)
+    }
+
+    /// Projects a single LL coordinate into sub-pixel coordinates using precomputed constants.
+    fn ll_to_subpixel_with<T: Coord>(ll: &T, bc: f64, cc: f64, d: f64) -> T {
+        let e = d + ll.x() * bc;
+        let f = ll.y().to_radians().sin().max(-0.9999).min(0.9999);
+        let g = d + 0.5 * ((1.0 + f) / (1.0 - f)).ln() * -cc;
+
+        T::with_xy(e, g)
+    }
+
+    /// Projects a single pixel position into LL coordinates using precomputed constants.
+    fn pixel_to_ll_with<T: Coord>(px: &T, bc: f64, cc: f64, d: f64) -> T {
+        let f = (px.x() - d) / bc;
+        let g = (px.y() - d) / -cc;
+        let h = (2.0 * g.exp().atan() - 0.5 * PI).to_degrees();
+
+        T::with_xy(f, h)
+    }
+
     /// Projects a given LL coordinate at a specific zoom level into decimal sub-pixel screen-coordinates.
     ///
     /// Zoom level is between 0 and 29 (inclusive). Every other zoom level will return a `None`.
+    ///
+    /// This is an inherent shortcut for the identically-named [`Projection`] method, so callers do
+    /// not have to bring the trait into scope for the default Mercator projection.
     pub fn from_ll_to_subpixel<T: Coord>(&self, ll: &T, zoom: usize) -> Option<T> {
-        if 30 > zoom {
-            let c = self.tile_size * 2.0_f64.powi(zoom as i32);
-            let bc = c / 360.0;
-            let cc = c / (2.0 * PI);
-
-            let d = c / 2.0;
-            let e = d + ll.x() * bc;
-            let f = ll.y().to_radians().sin().max(-0.9999).min(0.9999);
-            let g = d + 0.5 * ((1.0 + f) / (1.0 - f)).ln() * -cc;
-
-            Some(T::with_xy(e, g))
-        } else {
-            None
-        }
+        Projection::from_ll_to_subpixel(self, ll, zoom)
     }
 
+    /// Projects a given pixel position at a specific zoom level into LL world-coordinates.
+    ///
+    /// Zoom level is between 0 and 29 (inclusive). Every other zoom level will return a `None`.
+    ///
+    /// This is an inherent shortcut for the identically-named [`Projection`] method, so callers do
+    /// not have to bring the trait into scope for the default Mercator projection.
+    pub fn from_pixel_to_ll<T: Coord>(&self, px: &T, zoom: usize) -> Option<T> {
+        Projection::from_pixel_to_ll(self, px, zoom)
+    }
 
     /// Projects a given LL coordinate at a specific zoom level into integer pixel screen-coordinates.
     ///
@@ -45,21 +196,226 @@ impl Mercator {
         }
     }
 
+    /// Projects a whole sequence of LL coordinates at a specific zoom level into integer pixel
+    /// screen-coordinates.
+    ///
+    /// The per-zoom constants are computed once for the whole batch, making this substantially
+    /// faster than calling [`Mercator::from_ll_to_pixel`] in a loop when projecting polylines or
+    /// entire geometry arrays.
+    ///
+    /// Zoom level is between 0 and 29 (inclusive). Every other zoom level will return a `None`.
+    pub fn from_lls_to_pixels<'a, I, T>(&self, lls: I, zoom: usize) -> Option<Vec<T>>
+        where I: IntoIterator<Item = &'a T>,
+              T: Coord + 'a
+    {
+        if 30 > zoom {
+            let (bc, cc, d) = self.zoom_constants(zoom);
+
+            Some(lls.into_iter()
+                    .map(|ll| {
+                        let subpixel = Mercator::ll_to_subpixel_with(ll, bc, cc, d);
+                        T::with_xy((subpixel.x() + 0.5).floor(), (subpixel.y() + 0.5).floor())
+                    })
+                    .collect())
+        } else {
+            None
+        }
+    }
+
+    /// Projects a whole sequence of pixel positions at a specific zoom level back into LL
+    /// world-coordinates.
+    ///
+    /// The per-zoom constants are computed once for the whole batch, making this substantially
+    /// faster than calling [`Mercator::from_pixel_to_ll`] in a loop.
+    ///
+    /// Zoom level is between 0 and 29 (inclusive). Every other zoom level will return a `None`.
+    pub fn from_pixels_to_lls<'a, I, T>(&self, pxs: I, zoom: usize) -> Option<Vec<T>>
+        where I: IntoIterator<Item = &'a T>,
+              T: Coord + 'a
+    {
+        if 30 > zoom {
+            let (bc, cc, d) = self.zoom_constants(zoom);
+
+            Some(pxs.into_iter()
+                    .map(|px| Mercator::pixel_to_ll_with(px, bc, cc, d))
+                    .collect())
+        } else {
+            None
+        }
+    }
+
+    /// Projects a given LL coordinate into Spherical Web Mercator meters (EPSG:3857).
+    ///
+    /// Unlike the pixel projections this does not depend on a zoom level, so it always succeeds.
+    pub fn from_ll_to_meters<T: Coord>(&self, ll: &T) -> T {
+        let mx = ll.x() * ORIGIN_SHIFT / 180.0;
+        let my = ((90.0 + ll.y()) * PI / 360.0).tan().ln() / (PI / 180.0) * ORIGIN_SHIFT / 180.0;
+
+        T::with_xy(mx, my)
+    }
+
+    /// Projects a given EPSG:3857 meter position back into LL world-coordinates.
+    ///
+    /// Unlike the pixel projections this does not depend on a zoom level, so it always succeeds.
+    pub fn from_meters_to_ll<T: Coord>(&self, m: &T) -> T {
+        let lon = m.x() / ORIGIN_SHIFT * 180.0;
+        let lat = 180.0 / PI
+            * (2.0 * ((m.y() / ORIGIN_SHIFT * 180.0) * PI / 180.0).exp().atan() - PI / 2.0);
+
+        T::with_xy(lon, lat)
+    }
+
+    /// Projects a given EPSG:3857 meter position at a specific zoom level into pixel screen-coordinates.
+    ///
+    /// The pixel `y` axis counts up from the bottom edge of the map (TMS style), the opposite of
+    /// [`Mercator::from_ll_to_pixel`], whose `y` counts down from the top edge.
+    ///
+    /// Zoom level is between 0 and 29 (inclusive). Every other zoom level will return a `None`.
+    pub fn from_meters_to_pixel<T: Coord>(&self, m: &T, zoom: usize) -> Option<T> {
+        if 30 > zoom {
+            let res = (2.0 * ORIGIN_SHIFT) / (self.tile_size * 2.0_f64.powi(zoom as i32));
+
+            Some(T::with_xy(
+                (m.x() + ORIGIN_SHIFT) / res,
+                (m.y() + ORIGIN_SHIFT) / res,
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Projects a given pixel position at a specific zoom level into EPSG:3857 meters.
+    ///
+    /// The pixel `y` axis counts up from the bottom edge of the map, as in
+    /// [`Mercator::from_meters_to_pixel`].
+    ///
+    /// Zoom level is between 0 and 29 (inclusive). Every other zoom level will return a `None`.
+    pub fn from_pixel_to_meters<T: Coord>(&self, px: &T, zoom: usize) -> Option<T> {
+        if 30 > zoom {
+            let res = (2.0 * ORIGIN_SHIFT) / (self.tile_size * 2.0_f64.powi(zoom as i32));
+
+            Some(T::with_xy(
+                px.x() * res - ORIGIN_SHIFT,
+                px.y() * res - ORIGIN_SHIFT,
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Finds the tile a given pixel position falls inside at a specific zoom level.
+    ///
+    /// Zoom level is between 0 and 29 (inclusive). Every other zoom level will return a `None`.
+    pub fn from_pixel_to_tile<T: Coord>(&self, px: &T, zoom: usize) -> Option<Tile> {
+        if 30 > zoom {
+            Some(Tile {
+                x: (px.x() / self.tile_size).ceil() as i64 - 1,
+                y: (px.y() / self.tile_size).ceil() as i64 - 1,
+                z: zoom,
+                // Web Mercator pixels are numbered from the top-left, matching Google/XYZ.
+                origin: TileOrigin::Google,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Finds the tile a given LL coordinate falls inside at a specific zoom level.
+    ///
+    /// Zoom level is between 0 and 29 (inclusive). Every other zoom level will return a `None`.
+    pub fn from_ll_to_tile<T: Coord>(&self, ll: &T, zoom: usize) -> Option<Tile> {
+        self.from_ll_to_pixel(ll, zoom)
+            .and_then(|px| self.from_pixel_to_tile(&px, zoom))
+    }
+
+    /// Returns the LL coordinates of the two opposite corners of a tile.
+    ///
+    /// The corners are the tile's top-left pixel `(x * tile_size, y * tile_size)` and its
+    /// bottom-right pixel `((x + 1) * tile_size, (y + 1) * tile_size)` projected back into
+    /// world-coordinates. A TMS tile is first converted to Google/XYZ row ordering.
+    ///
+    /// The tile's zoom level is between 0 and 29 (inclusive). Any other zoom level returns `None`.
+    pub fn tile_bounds<T: Coord>(&self, tile: &Tile) -> Option<(T, T)> {
+        // Pixel rows count down from the top, so TMS rows are flipped first.
+        let tile = &tile.tms_to_google();
+        let top_left = T::with_xy(
+            tile.x as f64 * self.tile_size,
+            tile.y as f64 * self.tile_size,
+        );
+        let bottom_right = T::with_xy(
+            (tile.x + 1) as f64 * self.tile_size,
+            (tile.y + 1) as f64 * self.tile_size,
+        );
+
+        match (self.from_pixel_to_ll(&top_left, tile.z),
+               self.from_pixel_to_ll(&bottom_right, tile.z)) {
+            (Some(top_left), Some(bottom_right)) => Some((top_left, bottom_right)),
+            _ => None,
+        }
+    }
+
+    /// Returns the ground resolution in meters-per-pixel at a given latitude and zoom level.
+    ///
+    /// The resolution shrinks towards the poles because Web Mercator stretches the map there.
+    pub fn resolution(&self, lat: f64, zoom: usize) -> f64 {
+        (lat.to_radians().cos() * 2.0 * PI * EARTH_RADIUS)
+            / (self.tile_size * 2.0_f64.powi(zoom as i32))
+    }
+
+    /// Finds the smallest zoom level whose ground resolution at `lat` is at most `res`
+    /// meters-per-pixel, clamped to the `0..=29` range.
+    pub fn zoom_for_resolution(&self, res: f64, lat: f64) -> usize {
+        for zoom in 0..30 {
+            if self.resolution(lat, zoom) <= res {
+                return zoom;
+            }
+        }
+
+        29
+    }
+
+    /// Returns the map scale (as a unitless ratio) at a given latitude, zoom level and output `dpi`.
+    ///
+    /// This is the ground resolution multiplied by `dpi / 0.0254`, converting meters-per-pixel into
+    /// real-world meters per meter of printed map.
+    pub fn map_scale(&self, lat: f64, zoom: usize, dpi: f64) -> f64 {
+        self.resolution(lat, zoom) * dpi / 0.0254
+    }
+}
+
+/// A map projection that turns LL world-coordinates into screen-coordinates and back.
+///
+/// Implement this for a custom projection to reuse this crate's zoom, tile-size and `Coord`
+/// plumbing. [`Mercator`] is the default implementation and keeps the classic Web Mercator
+/// behavior; [`Equirectangular`] is a simple alternative.
+pub trait Projection {
+    /// Projects a given LL coordinate at a specific zoom level into decimal sub-pixel screen-coordinates.
+    ///
+    /// Zoom level is between 0 and 29 (inclusive). Every other zoom level will return a `None`.
+    fn from_ll_to_subpixel<T: Coord>(&self, ll: &T, zoom: usize) -> Option<T>;
+
     /// Projects a given pixel position at a specific zoom level into LL world-coordinates.
     ///
     /// Zoom level is between 0 and 29 (inclusive). Every other zoom level will return a `None`.
-    pub fn from_pixel_to_ll<T: Coord>(&self, px: &T, zoom: usize) -> Option<T> {
+    fn from_pixel_to_ll<T: Coord>(&self, px: &T, zoom: usize) -> Option<T>;
+}
+
+impl Projection for Mercator {
+    fn from_ll_to_subpixel<T: Coord>(&self, ll: &T, zoom: usize) -> Option<T> {
         if 30 > zoom {
-            let c = self.tile_size * 2.0_f64.powi(zoom as i32);
-            let bc = c / 360.0;
-            let cc = c / (2.0 * PI);
+            let (bc, cc, d) = self.zoom_constants(zoom);
+
+            Some(Mercator::ll_to_subpixel_with(ll, bc, cc, d))
+        } else {
+            None
+        }
+    }
 
-            let e = c / 2.0;
-            let f = (px.x() - e) / bc;
-            let g = (px.y() - e) / -cc;
-            let h = (2.0 * g.exp().atan() - 0.5 * PI).to_degrees();
+    fn from_pixel_to_ll<T: Coord>(&self, px: &T, zoom: usize) -> Option<T> {
+        if 30 > zoom {
+            let (bc, cc, d) = self.zoom_constants(zoom);
 
-            Some(T::with_xy(f, h))
+            Some(Mercator::pixel_to_ll_with(px, bc, cc, d))
         } else {
             None
         }
@@ -72,6 +428,55 @@ impl Default for Mercator {
     }
 }
 
+/// An equirectangular (Plate Carrée) projection, where longitude and latitude map linearly onto
+/// the screen.
+///
+/// It shares the same zoom and tile-size plumbing as [`Mercator`]; only the latitude axis differs,
+/// being linear rather than Mercator-warped.
+pub struct Equirectangular {
+    tile_size: f64,
+}
+
+impl Equirectangular {
+    /// Create a new Equirectangular with custom tile size. Tile sizes must be a power of two (256,
+    /// 512, and so on).
+    pub fn with_size(tile_size: usize) -> Equirectangular {
+        Equirectangular { tile_size: tile_size as f64 }
+    }
+}
+
+impl Default for Equirectangular {
+    fn default() -> Equirectangular {
+        Equirectangular { tile_size: 256.0 }
+    }
+}
+
+impl Projection for Equirectangular {
+    fn from_ll_to_subpixel<T: Coord>(&self, ll: &T, zoom: usize) -> Option<T> {
+        if 30 > zoom {
+            let c = self.tile_size * 2.0_f64.powi(zoom as i32);
+            let bc = c / 360.0;
+            let d = c / 2.0;
+
+            Some(T::with_xy(d + ll.x() * bc, d - ll.y() * bc))
+        } else {
+            None
+        }
+    }
+
+    fn from_pixel_to_ll<T: Coord>(&self, px: &T, zoom: usize) -> Option<T> {
+        if 30 > zoom {
+            let c = self.tile_size * 2.0_f64.powi(zoom as i32);
+            let bc = c / 360.0;
+            let d = c / 2.0;
+
+            Some(T::with_xy((px.x() - d) / bc, (d - px.y()) / bc))
+        } else {
+            None
+        }
+    }
+}
+
 /// Projects a given LL coordinate at a specific zoom level into decimal pixel screen-coordinates using a
 /// default tile size of 256.
 ///
@@ -156,6 +561,7 @@ impl Coord for (f64, f64) {
 #[cfg(test)]
 mod test {
     use Coord;
+    use Projection;
 
     const EPSILON: f64 = 1e-10;
 
@@ -163,6 +569,12 @@ mod test {
         ((pair.0 - expected.0).abs() < EPSILON) && ((pair.1 - expected.1).abs() < EPSILON)
     }
 
+    /// Like `float_pair_close` but with a caller-supplied tolerance, for comparing meter-magnitude
+    /// values where the formula's own round-off is coarser than `EPSILON`.
+    fn float_pair_within(pair: &(f64, f64), expected: &(f64, f64), tolerance: f64) -> bool {
+        ((pair.0 - expected.0).abs() < tolerance) && ((pair.1 - expected.1).abs() < tolerance)
+    }
+
     #[test]
     fn it_maps_coords_for_f64_tuple() {
         let coord: (f64, f64) = Coord::with_xy(45.0, 33.0);
@@ -311,6 +723,200 @@ mod test {
         }
     }
 
+    #[test]
+    fn it_projects_to_meters() {
+        let mercator = super::Mercator::default();
+
+        // The projection origin and the anti-meridian are reference points for EPSG:3857. The
+        // tolerance is meter-scale because `ln(tan(..))` does not round to an exact zero.
+        assert!(float_pair_within(&mercator.from_ll_to_meters(&(0.0, 0.0)), &(0.0, 0.0), 1e-4));
+
+        let edge = mercator.from_ll_to_meters(&(180.0, 0.0));
+        assert!((edge.0 - 20037508.342789244).abs() < 1e-4);
+        assert!(edge.1.abs() < 1e-4);
+    }
+
+    #[test]
+    fn it_round_trips_meters_through_ll() {
+        let mercator = super::Mercator::default();
+
+        for ll in vec![(13.2, 55.9), (-122.4194, 37.7749), (0.0, 0.0)] {
+            let meters = mercator.from_ll_to_meters(&ll);
+            let actual = mercator.from_meters_to_ll(&meters);
+
+            assert!(float_pair_close(&actual, &ll),
+                    format!("Expected {:?} to survive a meter round-trip but got {:?}", &ll, &actual));
+        }
+    }
+
+    #[test]
+    fn it_round_trips_meters_through_pixels() {
+        let mercator = super::Mercator::default();
+
+        for zoom in 0..5 {
+            let meters = mercator.from_ll_to_meters(&(13.2, 55.9));
+            let pixel = mercator.from_meters_to_pixel(&meters, zoom).unwrap();
+            let actual = mercator.from_pixel_to_meters(&pixel, zoom).unwrap();
+
+            assert!(float_pair_within(&actual, &meters, 1e-6),
+                    format!("Expected meters {:?} to survive a pixel round-trip at zoom {} but got {:?}",
+                            &meters, zoom, &actual));
+        }
+    }
+
+    #[test]
+    fn it_finds_the_tile_for_a_coordinate() {
+        let mercator = super::Mercator::default();
+
+        let tile = mercator.from_ll_to_tile(&(13.2, 55.9), 4).unwrap();
+        assert_eq!(tile.x, 8);
+        assert_eq!(tile.y, 4);
+        assert_eq!(tile.z, 4);
+    }
+
+    #[test]
+    fn it_flips_tile_rows_between_tms_and_google() {
+        use super::TileOrigin;
+
+        let mercator = super::Mercator::default();
+        let google = mercator.from_ll_to_tile(&(13.2, 55.9), 4).unwrap();
+        assert_eq!(google.origin, TileOrigin::Google);
+
+        let tms = google.google_to_tms();
+        assert_eq!(tms.origin, TileOrigin::Tms);
+        assert_eq!(tms.x, google.x);
+        assert_eq!(tms.y, (1 << google.z) - 1 - google.y);
+
+        // Flipping back lands on the original tile.
+        assert_eq!(tms.tms_to_google(), google);
+
+        // Converting to the origin a tile already has is a no-op.
+        assert_eq!(google.tms_to_google(), google);
+        assert_eq!(tms.google_to_tms(), tms);
+    }
+
+    #[test]
+    fn it_round_trips_tiles_through_quadkeys() {
+        use super::{Tile, TileOrigin};
+
+        let tile = Tile::from_quadkey("213").unwrap();
+        assert_eq!(tile.x, 3);
+        assert_eq!(tile.y, 5);
+        assert_eq!(tile.z, 3);
+        assert_eq!(tile.origin, TileOrigin::Google);
+
+        assert_eq!(tile.to_quadkey(), "213");
+    }
+
+    #[test]
+    fn it_rejects_invalid_quadkeys() {
+        use super::Tile;
+
+        assert_eq!(Tile::from_quadkey("2134"), None);
+        assert_eq!(Tile::from_quadkey("abc"), None);
+    }
+
+    #[test]
+    fn it_returns_tile_bounds_that_contain_the_coordinate() {
+        let mercator = super::Mercator::default();
+
+        let ll = (13.2, 55.9);
+        let tile = mercator.from_ll_to_tile(&ll, 4).unwrap();
+        let (top_left, bottom_right): ((f64, f64), (f64, f64)) = mercator.tile_bounds(&tile).unwrap();
+
+        // The top-left corner is north-west of the coordinate, the bottom-right south-east.
+        assert!(top_left.0 <= ll.0 && ll.0 <= bottom_right.0);
+        assert!(bottom_right.1 <= ll.1 && ll.1 <= top_left.1);
+    }
+
+    #[test]
+    fn it_returns_none_for_tile_bounds_out_of_range() {
+        use super::{Tile, TileOrigin};
+
+        let mercator = super::Mercator::default();
+        let tile = Tile { x: 0, y: 0, z: 30, origin: TileOrigin::Google };
+
+        let bounds: Option<((f64, f64), (f64, f64))> = mercator.tile_bounds(&tile);
+        assert_eq!(bounds, None);
+    }
+
+    #[test]
+    fn it_projects_batches_like_the_single_point_functions() {
+        let mercator = super::Mercator::default();
+        let lls = vec![(13.2, 55.9), (100.0, 54.0), (-45.0, 12.0)];
+
+        let batch: Vec<(f64, f64)> = mercator.from_lls_to_pixels(&lls, 12).unwrap();
+        for (ll, pixel) in lls.iter().zip(batch.iter()) {
+            let single = mercator.from_ll_to_pixel(ll, 12).unwrap();
+            assert!(float_pair_close(pixel, &single));
+        }
+
+        let back: Vec<(f64, f64)> = mercator.from_pixels_to_lls(&batch, 12).unwrap();
+        for (pixel, ll) in batch.iter().zip(back.iter()) {
+            let single = mercator.from_pixel_to_ll(pixel, 12).unwrap();
+            assert!(float_pair_close(ll, &single));
+        }
+    }
+
+    #[test]
+    fn it_returns_none_for_batches_when_zooming_too_far() {
+        let mercator = super::Mercator::default();
+        let lls = vec![(0.0, 0.0)];
+
+        assert_eq!(mercator.from_lls_to_pixels(&lls, 30), None);
+        assert_eq!(mercator.from_pixels_to_lls(&lls, 30), None);
+    }
+
+    #[test]
+    fn it_projects_equirectangularly() {
+        use super::Equirectangular;
+
+        let projection = Equirectangular::default();
+
+        // The origin lands in the middle of the zoom-0 tile, just like Mercator.
+        let origin = projection.from_ll_to_subpixel(&(0.0, 0.0), 0).unwrap();
+        assert!(float_pair_close(&origin, &(128.0, 128.0)));
+
+        // Latitude is linear, on the same scale as longitude, so 45 degrees north sits 32 pixels
+        // (45 * 256 / 360) above the middle of the tile.
+        let north = projection.from_ll_to_subpixel(&(0.0, 45.0), 0).unwrap();
+        assert!(float_pair_close(&north, &(128.0, 96.0)));
+
+        // And it round-trips back to the original coordinate.
+        let back = projection.from_pixel_to_ll(&north, 0).unwrap();
+        assert!(float_pair_close(&back, &(0.0, 45.0)));
+    }
+
+    #[test]
+    fn it_computes_ground_resolution_and_scale() {
+        let mercator = super::Mercator::default();
+
+        // The canonical Web Mercator resolution at the equator, zoom 0, 256px tiles.
+        assert!((mercator.resolution(0.0, 0) - 156543.03392804097).abs() < 1e-6);
+
+        // Every zoom level in halves the resolution.
+        assert!((mercator.resolution(0.0, 1) - mercator.resolution(0.0, 0) / 2.0).abs() < 1e-6);
+
+        // Map scale is the resolution scaled by the dots-per-meter of the output device.
+        let expected = mercator.resolution(0.0, 0) * 96.0 / 0.0254;
+        assert!((mercator.map_scale(0.0, 0, 96.0) - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn it_picks_a_zoom_for_a_target_resolution() {
+        let mercator = super::Mercator::default();
+
+        // A resolution just above zoom 0 resolves to zoom 0.
+        assert_eq!(mercator.zoom_for_resolution(200000.0, 0.0), 0);
+
+        // Asking for the exact zoom-2 resolution picks zoom 2.
+        let res = mercator.resolution(0.0, 2);
+        assert_eq!(mercator.zoom_for_resolution(res, 0.0), 2);
+
+        // An impossibly fine resolution clamps to the deepest zoom.
+        assert_eq!(mercator.zoom_for_resolution(1e-9, 0.0), 29);
+    }
+
     #[test]
     fn it_returns_none_when_zooming_too_far() {
         assert_eq!(super::from_ll_to_pixel(&(0.0, 0.0), 30), None);